@@ -1,23 +1,111 @@
-#![feature(str_as_str)]
-use std::{borrow::Cow, io::Write};
+use std::{
+    borrow::Cow,
+    io::{BufRead, Write},
+};
 
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, FixedOffset, TimeZone};
 use quick_xml::{
     events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event},
-    Error, Writer,
+    Error, Reader, Writer,
 };
 
 #[derive(Debug, Clone, Default)]
+pub enum TextType {
+    #[default]
+    Text,
+    Html,
+    Xhtml,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Text<'a> {
+    value: Cow<'a, str>,
+    text_type: TextType,
+}
+
+impl<'a> Text<'a> {
+    pub fn new<T>(value: T, text_type: TextType) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            value: value.into(),
+            text_type,
+        }
+    }
+
+    pub fn html<T>(value: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self::new(value, TextType::Html)
+    }
+
+    pub fn xhtml<T>(value: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self::new(value, TextType::Xhtml)
+    }
+
+    fn write<W: Write>(&self, writer: &mut Writer<W>, tag_name: &str) -> Result<(), Error> {
+        match self.text_type {
+            TextType::Text => {
+                writer
+                    .create_element(tag_name)
+                    .with_attribute(("type", "text"))
+                    .write_text_content(BytesText::new(&self.value))?;
+            }
+            TextType::Html => {
+                writer
+                    .create_element(tag_name)
+                    .with_attribute(("type", "html"))
+                    .write_text_content(BytesText::new(&self.value))?;
+            }
+            TextType::Xhtml => {
+                let mut tag = BytesStart::new(tag_name);
+                tag.push_attribute(("type", "xhtml"));
+                writer.write_event(Event::Start(tag))?;
+
+                let mut div = BytesStart::new("div");
+                div.push_attribute(("xmlns", "http://www.w3.org/1999/xhtml"));
+                writer.write_event(Event::Start(div))?;
+                writer.write_event(Event::Text(BytesText::from_escaped(self.value.as_ref())))?;
+                writer.write_event(Event::End(BytesEnd::new("div")))?;
+
+                writer.write_event(Event::End(BytesEnd::new(tag_name)))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a str> for Text<'a> {
+    fn from(value: &'a str) -> Self {
+        Text::new(value, TextType::Text)
+    }
+}
+
+impl From<String> for Text<'static> {
+    fn from(value: String) -> Self {
+        Text::new(value, TextType::Text)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct AtomFeed<'a, Tz: TimeZone> {
     generator: Option<Generator<'a>>,
     published: Option<DateTime<Tz>>,
     updated: Option<DateTime<Tz>>,
-    uri: Option<Cow<'a, str>>,
-    self_uri: Option<Cow<'a, str>>,
+    links: Vec<Link<'a>>,
     id: Option<Cow<'a, str>>,
-    title: Cow<'a, str>,
-    subtitle: Option<Cow<'a, str>>,
-    rights: Option<Cow<'a, str>>,
+    title: Text<'a>,
+    subtitle: Option<Text<'a>>,
+    rights: Option<Text<'a>>,
+    categories: Vec<Category<'a>>,
+    authors: Vec<Person<'a>>,
+    contributors: Vec<Person<'a>>,
     entries: Vec<AtomEntry<'a, Tz>>,
 }
 
@@ -29,18 +117,20 @@ where
 {
     pub fn new<T>(title: T) -> Self
     where
-        T: Into<Cow<'a, str>>,
+        T: Into<Text<'a>>,
     {
         Self(AtomFeed {
             title: title.into(),
             generator: None,
-            uri: None,
-            self_uri: None,
+            links: vec![],
             published: None,
             updated: None,
             id: None,
             subtitle: None,
             rights: None,
+            categories: vec![],
+            authors: vec![],
+            contributors: vec![],
             entries: vec![],
         })
     }
@@ -54,7 +144,9 @@ where
     where
         T: Into<Cow<'a, str>>,
     {
-        self.0.uri = Some(uri.into());
+        self.0
+            .links
+            .push(Link::new(uri).rel("alternate").link_type("text/html"));
         self
     }
 
@@ -62,7 +154,14 @@ where
     where
         T: Into<Cow<'a, str>>,
     {
-        self.0.self_uri = Some(uri.into());
+        self.0
+            .links
+            .push(Link::new(uri).rel("self").link_type("application/atom+xml"));
+        self
+    }
+
+    pub fn links<T>(mut self, links: Vec<Link<'a>>) -> Self {
+        self.0.links = links;
         self
     }
 
@@ -76,7 +175,7 @@ where
 
     pub fn subtitle<T>(mut self, subtitle: T) -> Self
     where
-        T: Into<Cow<'a, str>>,
+        T: Into<Text<'a>>,
     {
         self.0.subtitle = Some(subtitle.into());
         self
@@ -84,12 +183,27 @@ where
 
     pub fn rights<T>(mut self, rights: T) -> Self
     where
-        T: Into<Cow<'a, str>>,
+        T: Into<Text<'a>>,
     {
         self.0.rights = Some(rights.into());
         self
     }
 
+    pub fn categories<T>(mut self, categories: Vec<Category<'a>>) -> Self {
+        self.0.categories = categories;
+        self
+    }
+
+    pub fn authors<T>(mut self, authors: Vec<Person<'a>>) -> Self {
+        self.0.authors = authors;
+        self
+    }
+
+    pub fn contributors<T>(mut self, contributors: Vec<Person<'a>>) -> Self {
+        self.0.contributors = contributors;
+        self
+    }
+
     pub fn published<T>(mut self, published: DateTime<Tz>) -> Self {
         self.0.published = Some(published);
         self
@@ -114,12 +228,45 @@ impl<'a, Tz> AtomFeed<'a, Tz>
 where
     Tz: TimeZone,
 {
+    /// An empty feed with no title, used to seed [`FromXml`] parsing.
+    /// `#[derive(Default)]` isn't an option here since it would require
+    /// `Tz: Default`, which `chrono::FixedOffset` doesn't implement.
+    fn empty() -> Self {
+        Self {
+            generator: None,
+            published: None,
+            updated: None,
+            links: vec![],
+            id: None,
+            title: Text::default(),
+            subtitle: None,
+            rights: None,
+            categories: vec![],
+            authors: vec![],
+            contributors: vec![],
+            entries: vec![],
+        }
+    }
+
     pub fn write_to<W: Write>(&self, writer: W) -> Result<W, Error> {
         let mut w = ::quick_xml::Writer::new(writer);
         self.write(&mut w)?;
         Ok(w.into_inner())
     }
 
+    /// Like [`AtomFeed::write_to`], but pretty-prints the XML using the given
+    /// indentation character and width.
+    pub fn write_to_indented<W: Write>(
+        &self,
+        writer: W,
+        indent_char: u8,
+        indent_size: usize,
+    ) -> Result<W, Error> {
+        let mut w = ::quick_xml::Writer::new_with_indent(writer, indent_char, indent_size);
+        self.write(&mut w)?;
+        Ok(w.into_inner())
+    }
+
     fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), Error> {
         writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
         let mut tag = BytesStart::new("feed");
@@ -131,20 +278,8 @@ where
             generator.write(writer)?;
         }
 
-        if let Some(self_uri) = &self.self_uri {
-            let mut tag = BytesStart::new("link");
-            tag.push_attribute(("href", self_uri.as_str()));
-            tag.push_attribute(("rel", "self"));
-            tag.push_attribute(("type", "application/atom+xml"));
-            writer.write_event(Event::Empty(tag))?;
-        }
-
-        if let Some(uri) = &self.uri {
-            let mut tag = BytesStart::new("link");
-            tag.push_attribute(("href", uri.as_str()));
-            tag.push_attribute(("rel", "alternate"));
-            tag.push_attribute(("type", "text/html"));
-            writer.write_event(Event::Empty(tag))?;
+        for link in &self.links {
+            link.write(writer)?;
         }
 
         if let Some(published) = &self.published {
@@ -165,14 +300,34 @@ where
                 .write_text_content(BytesText::new(id))?;
         }
 
-        writer
-            .create_element("title")
-            .write_text_content(BytesText::new(&self.title))?;
+        self.title.write(writer, "title")?;
 
         if let Some(subtitle) = &self.subtitle {
-            writer
-                .create_element("subtitle")
-                .write_text_content(BytesText::new(subtitle))?;
+            subtitle.write(writer, "subtitle")?;
+        }
+
+        for author in &self.authors {
+            writer.write_event(Event::Start(BytesStart::new("author")))?;
+
+            author.write(writer)?;
+
+            writer.write_event(Event::End(BytesEnd::new("author")))?;
+        }
+
+        for contributor in &self.contributors {
+            writer.write_event(Event::Start(BytesStart::new("contributor")))?;
+
+            contributor.write(writer)?;
+
+            writer.write_event(Event::End(BytesEnd::new("contributor")))?;
+        }
+
+        if let Some(rights) = &self.rights {
+            rights.write(writer, "rights")?;
+        }
+
+        for category in &self.categories {
+            category.write(writer)?;
         }
 
         for entry in &self.entries {
@@ -223,11 +378,11 @@ impl<'a> Generator<'a> {
         let mut tag = BytesStart::new("generator");
 
         if let Some(uri) = &self.uri {
-            tag.push_attribute(("uri", uri.as_str()));
+            tag.push_attribute(("uri", uri.as_ref()));
         }
 
         if let Some(version) = &self.version {
-            tag.push_attribute(("version", version.as_str()));
+            tag.push_attribute(("version", version.as_ref()));
         }
 
         writer.write_event(Event::Start(tag))?;
@@ -237,6 +392,96 @@ impl<'a> Generator<'a> {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct Link<'a> {
+    href: Cow<'a, str>,
+    rel: Option<Cow<'a, str>>,
+    r#type: Option<Cow<'a, str>>,
+    hreflang: Option<Cow<'a, str>>,
+    title: Option<Cow<'a, str>>,
+    length: Option<u64>,
+}
+
+impl<'a> Link<'a> {
+    pub fn new<T>(href: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            href: href.into(),
+            rel: None,
+            r#type: None,
+            hreflang: None,
+            title: None,
+            length: None,
+        }
+    }
+
+    pub fn rel<T>(mut self, rel: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.rel = Some(rel.into());
+        self
+    }
+
+    pub fn link_type<T>(mut self, link_type: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.r#type = Some(link_type.into());
+        self
+    }
+
+    pub fn hreflang<T>(mut self, hreflang: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.hreflang = Some(hreflang.into());
+        self
+    }
+
+    pub fn title<T>(mut self, title: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), Error> {
+        let mut tag = BytesStart::new("link");
+        tag.push_attribute(("href", self.href.as_ref()));
+        tag.push_attribute(("rel", self.rel.as_deref().unwrap_or("alternate")));
+
+        if let Some(link_type) = &self.r#type {
+            tag.push_attribute(("type", link_type.as_ref()));
+        }
+
+        if let Some(hreflang) = &self.hreflang {
+            tag.push_attribute(("hreflang", hreflang.as_ref()));
+        }
+
+        if let Some(title) = &self.title {
+            tag.push_attribute(("title", title.as_ref()));
+        }
+
+        let length_str;
+        if let Some(length) = self.length {
+            length_str = length.to_string();
+            tag.push_attribute(("length", length_str.as_str()));
+        }
+
+        writer.write_event(Event::Empty(tag))?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Person<'a> {
     name: Cow<'a, str>,
@@ -293,37 +538,304 @@ impl<'a> Person<'a> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub enum Content<'a> {
+    Inline(Text<'a>),
+    External {
+        src: Cow<'a, str>,
+        content_type: Option<Cow<'a, str>>,
+    },
+}
+
+impl<'a> Content<'a> {
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), Error> {
+        match self {
+            Content::Inline(text) => text.write(writer, "content"),
+            Content::External { src, content_type } => {
+                let mut tag = BytesStart::new("content");
+                tag.push_attribute(("src", src.as_ref()));
+
+                if let Some(content_type) = content_type {
+                    tag.push_attribute(("type", content_type.as_ref()));
+                }
+
+                writer.write_event(Event::Empty(tag))?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a> From<Text<'a>> for Content<'a> {
+    fn from(text: Text<'a>) -> Self {
+        Content::Inline(text)
+    }
+}
+
+impl<'a> From<&'a str> for Content<'a> {
+    fn from(value: &'a str) -> Self {
+        Content::Inline(Text::from(value))
+    }
+}
+
+impl From<String> for Content<'static> {
+    fn from(value: String) -> Self {
+        Content::Inline(Text::from(value))
+    }
+}
+
 #[derive(Debug, Clone, Default)]
+pub struct Category<'a> {
+    term: Cow<'a, str>,
+    scheme: Option<Cow<'a, str>>,
+    label: Option<Cow<'a, str>>,
+}
+
+impl<'a> Category<'a> {
+    pub fn new<T>(term: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            term: term.into(),
+            scheme: None,
+            label: None,
+        }
+    }
+
+    pub fn scheme<T>(mut self, scheme: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.scheme = Some(scheme.into());
+        self
+    }
+
+    pub fn label<T>(mut self, label: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), Error> {
+        let mut tag = BytesStart::new("category");
+        tag.push_attribute(("term", self.term.as_ref()));
+
+        if let Some(scheme) = &self.scheme {
+            tag.push_attribute(("scheme", scheme.as_ref()));
+        }
+
+        if let Some(label) = &self.label {
+            tag.push_attribute(("label", label.as_ref()));
+        }
+
+        writer.write_event(Event::Empty(tag))?;
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a str> for Category<'a> {
+    fn from(value: &'a str) -> Self {
+        Category::new(value)
+    }
+}
+
+impl From<String> for Category<'static> {
+    fn from(value: String) -> Self {
+        Category::new(value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Source<'a, Tz: TimeZone> {
+    id: Option<Cow<'a, str>>,
+    title: Text<'a>,
+    subtitle: Option<Text<'a>>,
+    updated: Option<DateTime<Tz>>,
+    generator: Option<Generator<'a>>,
+    links: Vec<Link<'a>>,
+    authors: Vec<Person<'a>>,
+    rights: Option<Text<'a>>,
+}
+
+impl<'a, Tz> Source<'a, Tz>
+where
+    Tz: TimeZone,
+{
+    /// An empty source with no title, used to seed [`FromXml`] parsing. See
+    /// [`AtomFeed::empty`] for why this can't be a derived `Default`.
+    fn empty() -> Self {
+        Self {
+            id: None,
+            title: Text::default(),
+            subtitle: None,
+            updated: None,
+            generator: None,
+            links: vec![],
+            authors: vec![],
+            rights: None,
+        }
+    }
+
+    pub fn new<T>(title: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            title: title.into(),
+            id: None,
+            subtitle: None,
+            updated: None,
+            generator: None,
+            links: vec![],
+            authors: vec![],
+            rights: None,
+        }
+    }
+
+    pub fn id<T>(mut self, id: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn subtitle<T>(mut self, subtitle: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn updated<T>(mut self, updated: DateTime<Tz>) -> Self {
+        self.updated = Some(updated);
+        self
+    }
+
+    pub fn generator<T>(mut self, generator: Generator<'a>) -> Self {
+        self.generator = Some(generator);
+        self
+    }
+
+    pub fn links<T>(mut self, links: Vec<Link<'a>>) -> Self {
+        self.links = links;
+        self
+    }
+
+    pub fn authors<T>(mut self, authors: Vec<Person<'a>>) -> Self {
+        self.authors = authors;
+        self
+    }
+
+    pub fn rights<T>(mut self, rights: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        self.rights = Some(rights.into());
+        self
+    }
+
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), Error> {
+        writer.write_event(Event::Start(BytesStart::new("source")))?;
+
+        if let Some(generator) = &self.generator {
+            generator.write(writer)?;
+        }
+
+        for link in &self.links {
+            link.write(writer)?;
+        }
+
+        if let Some(id) = &self.id {
+            writer
+                .create_element("id")
+                .write_text_content(BytesText::new(id))?;
+        }
+
+        self.title.write(writer, "title")?;
+
+        if let Some(subtitle) = &self.subtitle {
+            subtitle.write(writer, "subtitle")?;
+        }
+
+        for author in &self.authors {
+            writer.write_event(Event::Start(BytesStart::new("author")))?;
+
+            author.write(writer)?;
+
+            writer.write_event(Event::End(BytesEnd::new("author")))?;
+        }
+
+        if let Some(rights) = &self.rights {
+            rights.write(writer, "rights")?;
+        }
+
+        if let Some(updated) = &self.updated {
+            writer
+                .create_element("updated")
+                .write_text_content(BytesText::new(&updated.to_rfc3339()))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("source")))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct AtomEntry<'a, Tz: TimeZone> {
-    title: Cow<'a, str>,
-    uri: Option<Cow<'a, str>>,
+    title: Text<'a>,
+    links: Vec<Link<'a>>,
     published: Option<DateTime<Tz>>,
     updated: Option<DateTime<Tz>>,
     id: Option<Cow<'a, str>>,
-    categories: Vec<Cow<'a, str>>,
+    categories: Vec<Category<'a>>,
     authors: Vec<Person<'a>>,
     contributors: Vec<Person<'a>>,
-    content: Option<Cow<'a, str>>,
-    summary: Option<Cow<'a, str>>,
+    source: Option<Source<'a, Tz>>,
+    content: Option<Content<'a>>,
+    summary: Option<Text<'a>>,
 }
 
 impl<'a, Tz> AtomEntry<'a, Tz>
 where
     Tz: TimeZone,
 {
+    /// An empty entry with no title, used to seed [`FromXml`] parsing. See
+    /// [`AtomFeed::empty`] for why this can't be a derived `Default`.
+    fn empty() -> Self {
+        Self {
+            title: Text::default(),
+            links: vec![],
+            published: None,
+            updated: None,
+            id: None,
+            categories: vec![],
+            authors: vec![],
+            contributors: vec![],
+            source: None,
+            content: None,
+            summary: None,
+        }
+    }
+
     pub fn new<T>(title: T) -> Self
     where
-        T: Into<Cow<'a, str>>,
+        T: Into<Text<'a>>,
     {
         Self {
             title: title.into(),
-            uri: None,
+            links: vec![],
             published: None,
             updated: None,
             id: None,
             categories: vec![],
             authors: vec![],
             contributors: vec![],
+            source: None,
             content: None,
             summary: None,
         }
@@ -333,7 +845,18 @@ where
     where
         T: Into<Cow<'a, str>>,
     {
-        self.uri = Some(uri.into());
+        let title = self.title.value.clone();
+        self.links.push(
+            Link::new(uri)
+                .rel("alternate")
+                .link_type("text/html")
+                .title(title),
+        );
+        self
+    }
+
+    pub fn links<T>(mut self, links: Vec<Link<'a>>) -> Self {
+        self.links = links;
         self
     }
 
@@ -355,7 +878,7 @@ where
         self
     }
 
-    pub fn categories<T>(mut self, categories: Vec<Cow<'a, str>>) -> Self {
+    pub fn categories<T>(mut self, categories: Vec<Category<'a>>) -> Self {
         self.categories = categories;
         self
     }
@@ -370,36 +893,92 @@ where
         self
     }
 
+    pub fn source(mut self, source: Source<'a, Tz>) -> Self {
+        self.source = Some(source);
+        self
+    }
+
     pub fn content<T>(mut self, content: T) -> Self
     where
-        T: Into<Cow<'a, str>>,
+        T: Into<Content<'a>>,
     {
         self.content = Some(content.into());
         self
     }
 
-    pub fn summary<T>(mut self, summary: T) -> Self
+    pub fn content_src<T>(mut self, uri: T) -> Self
     where
         T: Into<Cow<'a, str>>,
+    {
+        let content_type = match self.content.take() {
+            Some(Content::External { content_type, .. }) => content_type,
+            _ => None,
+        };
+        self.content = Some(Content::External {
+            src: uri.into(),
+            content_type,
+        });
+        self
+    }
+
+    /// Sets the media type of an out-of-line `content` element. Pair this
+    /// with [`AtomEntry::content_src`] (in either order); it has no effect
+    /// on inline content set via [`AtomEntry::content`].
+    pub fn content_type<T>(mut self, mime: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let src = match self.content {
+            Some(Content::External { ref src, .. }) => src.clone(),
+            Some(Content::Inline(_)) => return self,
+            None => Cow::Borrowed(""),
+        };
+        self.content = Some(Content::External {
+            src,
+            content_type: Some(mime.into()),
+        });
+        self
+    }
+
+    pub fn summary<T>(mut self, summary: T) -> Self
+    where
+        T: Into<Text<'a>>,
     {
         self.summary = Some(summary.into());
         self
     }
 
+    /// Renders `md` from CommonMark to HTML and sets it as the entry's
+    /// inline content, analogous to [`AtomEntry::content`].
+    #[cfg(feature = "markdown")]
+    pub fn content_markdown<T>(mut self, md: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let html = markdown_to_html(md.as_ref());
+        self.content = Some(Content::Inline(Text::new(html, TextType::Html)));
+        self
+    }
+
+    /// Renders `md` from CommonMark to HTML and sets it as the entry's
+    /// summary, analogous to [`AtomEntry::summary`].
+    #[cfg(feature = "markdown")]
+    pub fn summary_markdown<T>(mut self, md: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let html = markdown_to_html(md.as_ref());
+        self.summary = Some(Text::new(html, TextType::Html));
+        self
+    }
+
     fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), Error> {
         writer.write_event(Event::Start(BytesStart::new("entry")))?;
 
-        writer
-            .create_element("title")
-            .write_text_content(BytesText::new(&self.title))?;
+        self.title.write(writer, "title")?;
 
-        if let Some(uri) = &self.uri {
-            let mut element = BytesStart::new("link");
-            element.push_attribute(("href", uri.as_str()));
-            element.push_attribute(("rel", "alternate"));
-            element.push_attribute(("type", "text/html"));
-            element.push_attribute(("title", self.title.as_str()));
-            writer.write_event(Event::Empty(element))?;
+        for link in &self.links {
+            link.write(writer)?;
         }
 
         if let Some(published) = &self.published {
@@ -437,26 +1016,703 @@ where
         }
 
         for category in &self.categories {
-            let mut tag = BytesStart::new("category");
-            tag.push_attribute(("term", category.as_str()));
-            writer.write_event(Event::Empty(tag))?;
+            category.write(writer)?;
+        }
+
+        if let Some(source) = &self.source {
+            source.write(writer)?;
         }
 
         if let Some(summary) = &self.summary {
-            writer
-                .create_element("summary")
-                .with_attribute(("type", "html"))
-                .write_text_content(BytesText::new(summary))?;
+            summary.write(writer, "summary")?;
         }
 
         if let Some(content) = &self.content {
-            writer
-                .create_element("content")
-                .with_attribute(("type", "html"))
-                .write_text_content(BytesText::new(content))?;
+            content.write(writer)?;
         }
 
         writer.write_event(Event::End(BytesEnd::new("entry")))?;
         Ok(())
     }
 }
+
+/// Reconstructs a value from a stream of XML events, mirroring the `write`
+/// methods above. Implementors are handed the already-read start (or
+/// empty-element) tag that introduced them and are expected to consume
+/// events up to and including their own matching end tag.
+trait FromXml<'a>: Sized {
+    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error>;
+}
+
+fn owned_attr(attr: ::quick_xml::events::attributes::Attribute) -> Result<Cow<'static, str>, Error> {
+    Ok(Cow::Owned(attr.unescape_value()?.into_owned()))
+}
+
+fn invalid_data(msg: impl Into<String>) -> Error {
+    Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, msg.into()).into())
+}
+
+#[cfg(feature = "markdown")]
+fn markdown_to_html(md: &str) -> String {
+    let parser = ::pulldown_cmark::Parser::new(md);
+    let mut html = String::new();
+    ::pulldown_cmark::html::push_html(&mut html, parser);
+    html
+}
+
+fn parse_rfc3339(value: &str) -> Result<DateTime<FixedOffset>, Error> {
+    DateTime::parse_from_rfc3339(value).map_err(|e| invalid_data(e.to_string()))
+}
+
+/// Reads the text content of a simple (non-XHTML) element up to its
+/// matching end tag. `Reader<R>` only exposes `read_event_into` for a
+/// generic `R: BufRead`, so this walks events by hand instead of relying
+/// on the slice-reader-only `read_text` convenience method.
+fn read_text_to_end<R: BufRead>(reader: &mut Reader<R>, end_name: &[u8]) -> Result<String, Error> {
+    let mut buf = Vec::new();
+    let mut out = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Text(e) => out.push_str(&e.unescape()?),
+            Event::End(e) if e.name().as_ref() == end_name => break,
+            Event::Eof => return Err(invalid_data("unexpected eof while parsing <text>")),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(out)
+}
+
+/// Parses a title/subtitle/rights/summary/content text construct, reading
+/// its `type` attribute off `start` to decide how the body is encoded.
+fn read_text_construct<R: BufRead>(
+    reader: &mut Reader<R>,
+    start: &BytesStart,
+) -> Result<Text<'static>, Error> {
+    let mut text_type = TextType::Text;
+
+    for attr in start.attributes() {
+        let attr = attr?;
+        if attr.key.as_ref() == b"type" {
+            text_type = match owned_attr(attr)?.as_ref() {
+                "html" => TextType::Html,
+                "xhtml" => TextType::Xhtml,
+                _ => TextType::Text,
+            };
+        }
+    }
+
+    let value = if matches!(text_type, TextType::Xhtml) {
+        read_xhtml(reader, start.name().as_ref())?
+    } else {
+        read_text_to_end(reader, start.name().as_ref())?
+    };
+
+    Ok(Text::new(value, text_type))
+}
+
+/// Parses an out-of-line `<content src="..." type="..."/>` element.
+fn read_external_content(start: &BytesStart) -> Result<Content<'static>, Error> {
+    let mut src = Cow::Borrowed("");
+    let mut content_type = None;
+
+    for attr in start.attributes() {
+        let attr = attr?;
+        match attr.key.as_ref() {
+            b"src" => src = owned_attr(attr)?,
+            b"type" => content_type = Some(owned_attr(attr)?),
+            _ => {}
+        }
+    }
+
+    Ok(Content::External { src, content_type })
+}
+
+/// Reads the raw markup inside an xhtml text construct's wrapping
+/// `<div xmlns="...">`, the inverse of the wrapping done in `Text::write`.
+fn read_xhtml<R: BufRead>(reader: &mut Reader<R>, end_name: &[u8]) -> Result<String, Error> {
+    let mut buf = Vec::new();
+    let mut out = Writer::new(Vec::new());
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::End(e) if e.name().as_ref() == end_name => break,
+            Event::Eof => return Err(invalid_data("unexpected eof while parsing <xhtml>")),
+            event => out.write_event(event.into_owned())?,
+        }
+        buf.clear();
+    }
+
+    let xml = String::from_utf8(out.into_inner()).map_err(|e| invalid_data(e.to_string()))?;
+
+    Ok(xml
+        .strip_prefix("<div xmlns=\"http://www.w3.org/1999/xhtml\">")
+        .and_then(|s| s.strip_suffix("</div>"))
+        .unwrap_or(&xml)
+        .to_string())
+}
+
+impl<'a> FromXml<'a> for Generator<'a> {
+    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error> {
+        let mut generator = Generator::default();
+
+        for attr in start.attributes() {
+            let attr = attr?;
+            match attr.key.as_ref() {
+                b"uri" => generator.uri = Some(owned_attr(attr)?),
+                b"version" => generator.version = Some(owned_attr(attr)?),
+                _ => {}
+            }
+        }
+
+        generator.name = Cow::Owned(read_text_to_end(reader, start.name().as_ref())?);
+
+        Ok(generator)
+    }
+}
+
+impl<'a> FromXml<'a> for Person<'a> {
+    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error> {
+        let end_name = start.name().as_ref().to_vec();
+        let mut person = Person::default();
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.local_name().as_ref() {
+                    b"name" => person.name = Cow::Owned(read_text_to_end(reader, e.name().as_ref())?),
+                    b"uri" => person.uri = Some(Cow::Owned(read_text_to_end(reader, e.name().as_ref())?)),
+                    b"email" => person.email = Some(Cow::Owned(read_text_to_end(reader, e.name().as_ref())?)),
+                    _ => {
+                        reader.read_to_end_into(e.name(), &mut Vec::new())?;
+                    }
+                },
+                Event::End(e) if e.name().as_ref() == end_name => break,
+                Event::Eof => return Err(invalid_data("unexpected eof while parsing <author>")),
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(person)
+    }
+}
+
+impl<'a> FromXml<'a> for Link<'a> {
+    fn from_xml<R: BufRead>(_reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error> {
+        let mut link = Link::default();
+
+        for attr in start.attributes() {
+            let attr = attr?;
+            match attr.key.as_ref() {
+                b"href" => link.href = owned_attr(attr)?,
+                b"rel" => link.rel = Some(owned_attr(attr)?),
+                b"type" => link.r#type = Some(owned_attr(attr)?),
+                b"hreflang" => link.hreflang = Some(owned_attr(attr)?),
+                b"title" => link.title = Some(owned_attr(attr)?),
+                b"length" => link.length = owned_attr(attr)?.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Ok(link)
+    }
+}
+
+impl<'a> FromXml<'a> for Category<'a> {
+    fn from_xml<R: BufRead>(_reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error> {
+        let mut category = Category::default();
+
+        for attr in start.attributes() {
+            let attr = attr?;
+            match attr.key.as_ref() {
+                b"term" => category.term = owned_attr(attr)?,
+                b"scheme" => category.scheme = Some(owned_attr(attr)?),
+                b"label" => category.label = Some(owned_attr(attr)?),
+                _ => {}
+            }
+        }
+
+        Ok(category)
+    }
+}
+
+impl<'a> FromXml<'a> for Source<'a, FixedOffset> {
+    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error> {
+        let end_name = start.name().as_ref().to_vec();
+        let mut source = Source::empty();
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.local_name().as_ref() {
+                    b"title" => source.title = read_text_construct(reader, &e)?,
+                    b"subtitle" => source.subtitle = Some(read_text_construct(reader, &e)?),
+                    b"rights" => source.rights = Some(read_text_construct(reader, &e)?),
+                    b"id" => source.id = Some(Cow::Owned(read_text_to_end(reader, e.name().as_ref())?)),
+                    b"updated" => {
+                        source.updated =
+                            Some(parse_rfc3339(&read_text_to_end(reader, e.name().as_ref())?)?)
+                    }
+                    b"generator" => source.generator = Some(Generator::from_xml(reader, &e)?),
+                    b"author" => source.authors.push(Person::from_xml(reader, &e)?),
+                    _ => {
+                        reader.read_to_end_into(e.name(), &mut Vec::new())?;
+                    }
+                },
+                Event::Empty(e) if e.local_name().as_ref() == b"link" => {
+                    source.links.push(Link::from_xml(reader, &e)?);
+                }
+                Event::End(e) if e.name().as_ref() == end_name => break,
+                Event::Eof => return Err(invalid_data("unexpected eof while parsing <source>")),
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(source)
+    }
+}
+
+impl<'a> FromXml<'a> for AtomEntry<'a, FixedOffset> {
+    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error> {
+        let end_name = start.name().as_ref().to_vec();
+        let mut entry = AtomEntry::empty();
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.local_name().as_ref() {
+                    b"title" => entry.title = read_text_construct(reader, &e)?,
+                    b"id" => entry.id = Some(Cow::Owned(read_text_to_end(reader, e.name().as_ref())?)),
+                    b"published" => {
+                        entry.published =
+                            Some(parse_rfc3339(&read_text_to_end(reader, e.name().as_ref())?)?)
+                    }
+                    b"updated" => {
+                        entry.updated =
+                            Some(parse_rfc3339(&read_text_to_end(reader, e.name().as_ref())?)?)
+                    }
+                    b"summary" => entry.summary = Some(read_text_construct(reader, &e)?),
+                    b"content" => entry.content = Some(read_text_construct(reader, &e)?.into()),
+                    b"author" => entry.authors.push(Person::from_xml(reader, &e)?),
+                    b"contributor" => entry.contributors.push(Person::from_xml(reader, &e)?),
+                    b"source" => entry.source = Some(Source::from_xml(reader, &e)?),
+                    _ => {
+                        reader.read_to_end_into(e.name(), &mut Vec::new())?;
+                    }
+                },
+                Event::Empty(e) => match e.local_name().as_ref() {
+                    b"link" => entry.links.push(Link::from_xml(reader, &e)?),
+                    b"content" => entry.content = Some(read_external_content(&e)?),
+                    b"category" => entry.categories.push(Category::from_xml(reader, &e)?),
+                    _ => {}
+                },
+                Event::End(e) if e.name().as_ref() == end_name => break,
+                Event::Eof => return Err(invalid_data("unexpected eof while parsing <entry>")),
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(entry)
+    }
+}
+
+impl<'a> FromXml<'a> for AtomFeed<'a, FixedOffset> {
+    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start: &BytesStart) -> Result<Self, Error> {
+        let end_name = start.name().as_ref().to_vec();
+        let mut feed = AtomFeed::empty();
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match e.local_name().as_ref() {
+                    b"title" => feed.title = read_text_construct(reader, &e)?,
+                    b"subtitle" => feed.subtitle = Some(read_text_construct(reader, &e)?),
+                    b"rights" => feed.rights = Some(read_text_construct(reader, &e)?),
+                    b"id" => feed.id = Some(Cow::Owned(read_text_to_end(reader, e.name().as_ref())?)),
+                    b"published" => {
+                        feed.published =
+                            Some(parse_rfc3339(&read_text_to_end(reader, e.name().as_ref())?)?)
+                    }
+                    b"updated" => {
+                        feed.updated =
+                            Some(parse_rfc3339(&read_text_to_end(reader, e.name().as_ref())?)?)
+                    }
+                    b"generator" => feed.generator = Some(Generator::from_xml(reader, &e)?),
+                    b"author" => feed.authors.push(Person::from_xml(reader, &e)?),
+                    b"contributor" => feed.contributors.push(Person::from_xml(reader, &e)?),
+                    b"entry" => feed.entries.push(AtomEntry::from_xml(reader, &e)?),
+                    _ => {
+                        reader.read_to_end_into(e.name(), &mut Vec::new())?;
+                    }
+                },
+                Event::Empty(e) => match e.local_name().as_ref() {
+                    b"link" => feed.links.push(Link::from_xml(reader, &e)?),
+                    b"category" => feed.categories.push(Category::from_xml(reader, &e)?),
+                    _ => {}
+                },
+                Event::End(e) if e.name().as_ref() == end_name => break,
+                Event::Eof => return Err(invalid_data("unexpected eof while parsing <feed>")),
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(feed)
+    }
+}
+
+impl<'a> AtomFeed<'a, FixedOffset> {
+    /// Parses an Atom document back into an `AtomFeed`, the read-side
+    /// counterpart to [`AtomFeed::write_to`].
+    pub fn read_from<R: BufRead>(reader: R) -> Result<Self, Error> {
+        let mut reader = Reader::from_reader(reader);
+        reader.config_mut().trim_text(true);
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) if e.local_name().as_ref() == b"feed" => {
+                    return AtomFeed::from_xml(&mut reader, &e);
+                }
+                Event::Eof => return Err(invalid_data("unexpected eof while parsing <feed>")),
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct JsonFeed<'a> {
+    version: &'static str,
+    title: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_page_url: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    feed_url: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<JsonAuthor<'a>>,
+    items: Vec<JsonItem<'a>>,
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct JsonAuthor<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<Cow<'a, str>>,
+}
+
+#[cfg(feature = "json")]
+impl<'a> From<&Person<'a>> for JsonAuthor<'a> {
+    fn from(person: &Person<'a>) -> Self {
+        Self {
+            name: Some(person.name.clone()),
+            url: person.uri.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(serde::Serialize)]
+struct JsonItem<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<Cow<'a, str>>,
+    title: Cow<'a, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_html: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_modified: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<JsonAuthor<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<Cow<'a, str>>,
+}
+
+#[cfg(feature = "json")]
+impl<'a, Tz> From<&AtomEntry<'a, Tz>> for JsonItem<'a>
+where
+    Tz: TimeZone,
+{
+    fn from(entry: &AtomEntry<'a, Tz>) -> Self {
+        let content_html = match &entry.content {
+            Some(Content::Inline(text)) => Some(text.value.clone()),
+            _ => None,
+        };
+
+        Self {
+            id: entry.id.clone(),
+            url: entry.links.first().map(|link| link.href.clone()),
+            title: entry.title.value.clone(),
+            content_html,
+            summary: entry.summary.as_ref().map(|text| text.value.clone()),
+            date_published: entry.published.as_ref().map(DateTime::to_rfc3339),
+            date_modified: entry.updated.as_ref().map(DateTime::to_rfc3339),
+            authors: entry.authors.iter().map(JsonAuthor::from).collect(),
+            tags: entry.categories.iter().map(|c| c.term.clone()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a, Tz> AtomFeed<'a, Tz>
+where
+    Tz: TimeZone,
+{
+    /// Serializes this feed as a JSON Feed 1.1 document, an alternative to
+    /// [`AtomFeed::write_to`] for consumers that prefer JSON over Atom XML.
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<(), serde_json::Error> {
+        let home_page_url = self
+            .links
+            .iter()
+            .find(|link| matches!(link.rel.as_deref(), Some("alternate") | None))
+            .map(|link| link.href.clone());
+
+        let feed_url = self
+            .links
+            .iter()
+            .find(|link| link.rel.as_deref() == Some("self"))
+            .map(|link| link.href.clone());
+
+        let feed = JsonFeed {
+            version: "https://jsonfeed.org/version/1.1",
+            title: self.title.value.clone(),
+            home_page_url,
+            feed_url,
+            description: self.subtitle.as_ref().map(|text| text.value.clone()),
+            authors: self.authors.iter().map(JsonAuthor::from).collect(),
+            items: self.entries.iter().map(JsonItem::from).collect(),
+        };
+
+        serde_json::to_writer(writer, &feed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_write_to_and_read_from() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .id("urn:uuid:feed-1")
+            .uri("https://example.com/")
+            .entries::<&str>(vec![AtomEntry::new("Entry One")
+                .id("urn:uuid:entry-1")
+                .content("Hello, world!")])
+            .build();
+
+        let xml = feed.write_to(Vec::new()).unwrap();
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+
+        assert_eq!(parsed.title.value, "Example Feed");
+        assert_eq!(parsed.id.as_deref(), Some("urn:uuid:feed-1"));
+        assert_eq!(parsed.entries.len(), 1);
+        assert_eq!(parsed.entries[0].title.value, "Entry One");
+    }
+
+    #[test]
+    fn link_attributes_round_trip() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .links::<&str>(vec![Link::new("https://example.com/episode.mp3")
+                .rel("enclosure")
+                .link_type("audio/mpeg")
+                .hreflang("en")
+                .length(123456)])
+            .entries::<&str>(vec![])
+            .build();
+
+        let xml = feed.write_to(Vec::new()).unwrap();
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+
+        let link = &parsed.links[0];
+        assert_eq!(link.href, "https://example.com/episode.mp3");
+        assert_eq!(link.rel.as_deref(), Some("enclosure"));
+        assert_eq!(link.r#type.as_deref(), Some("audio/mpeg"));
+        assert_eq!(link.hreflang.as_deref(), Some("en"));
+        assert_eq!(link.length, Some(123456));
+    }
+
+    #[test]
+    fn xhtml_content_round_trips_through_its_wrapping_div() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .entries::<&str>(vec![AtomEntry::new("Entry One")
+                .content(Text::xhtml("<p>Hello &amp; welcome</p>"))])
+            .build();
+
+        let xml = feed.write_to(Vec::new()).unwrap();
+        let xml_str = String::from_utf8(xml.clone()).unwrap();
+        assert!(xml_str.contains("xmlns=\"http://www.w3.org/1999/xhtml\""));
+
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+        match &parsed.entries[0].content {
+            Some(Content::Inline(text)) => {
+                assert!(matches!(text.text_type, TextType::Xhtml));
+                assert_eq!(text.value, "<p>Hello &amp; welcome</p>");
+            }
+            other => panic!("expected inline xhtml content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn external_content_round_trips_src_and_type() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .entries::<&str>(vec![AtomEntry::new("Entry One")
+                .content_src("https://example.com/episode.mp3")
+                .content_type("audio/mpeg")])
+            .build();
+
+        let xml = feed.write_to(Vec::new()).unwrap();
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+
+        match &parsed.entries[0].content {
+            Some(Content::External { src, content_type }) => {
+                assert_eq!(src, "https://example.com/episode.mp3");
+                assert_eq!(content_type.as_deref(), Some("audio/mpeg"));
+            }
+            other => panic!("expected external content, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn content_type_does_not_clobber_inline_content() {
+        let entry = AtomEntry::<chrono::Utc>::new("Entry One")
+            .content("Hello, world!")
+            .content_type("audio/mpeg");
+
+        assert!(matches!(entry.content, Some(Content::Inline(_))));
+    }
+
+    #[test]
+    fn feed_categories_round_trip_scheme_and_label() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .categories::<&str>(vec![Category::new("tech")
+                .scheme("https://example.com/categories")
+                .label("Technology")])
+            .entries::<&str>(vec![])
+            .build();
+
+        let xml = feed.write_to(Vec::new()).unwrap();
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+
+        let category = &parsed.categories[0];
+        assert_eq!(category.term, "tech");
+        assert_eq!(category.scheme.as_deref(), Some("https://example.com/categories"));
+        assert_eq!(category.label.as_deref(), Some("Technology"));
+    }
+
+    #[test]
+    fn feed_authors_and_contributors_round_trip() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .authors::<&str>(vec![Person::new("Jane Doe")
+                .uri("https://example.com/jane")
+                .email("jane@example.com")])
+            .contributors::<&str>(vec![Person::new("John Smith")])
+            .entries::<&str>(vec![])
+            .build();
+
+        let xml = feed.write_to(Vec::new()).unwrap();
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+
+        let author = &parsed.authors[0];
+        assert_eq!(author.name, "Jane Doe");
+        assert_eq!(author.uri.as_deref(), Some("https://example.com/jane"));
+        assert_eq!(author.email.as_deref(), Some("jane@example.com"));
+
+        assert_eq!(parsed.contributors[0].name, "John Smith");
+    }
+
+    #[test]
+    fn entry_source_round_trips() {
+        let source = Source::<chrono::Utc>::new("Original Feed")
+            .id("urn:uuid:source-1")
+            .generator::<&str>(Generator::new("example-generator"))
+            .links::<&str>(vec![Link::new("https://example.com/").rel("alternate")])
+            .authors::<&str>(vec![Person::new("Jane Doe")]);
+
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .entries::<&str>(vec![AtomEntry::new("Entry One").source(source)])
+            .build();
+
+        let xml = feed.write_to(Vec::new()).unwrap();
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+
+        let source = parsed.entries[0].source.as_ref().expect("source");
+        assert_eq!(source.title.value, "Original Feed");
+        assert_eq!(source.id.as_deref(), Some("urn:uuid:source-1"));
+        assert_eq!(source.authors[0].name, "Jane Doe");
+        assert_eq!(source.links[0].href, "https://example.com/");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_feed_picks_the_alternate_link_as_home_page_url() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .links::<&str>(vec![
+                Link::new("https://example.com/enclosure.mp3").rel("enclosure"),
+                Link::new("https://example.com/").rel("alternate"),
+                Link::new("https://example.com/feed.xml").rel("self"),
+            ])
+            .entries::<&str>(vec![])
+            .build();
+
+        let mut json = Vec::new();
+        feed.write_json(&mut json).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(value["home_page_url"], "https://example.com/");
+        assert_eq!(value["feed_url"], "https://example.com/feed.xml");
+    }
+
+    #[test]
+    fn write_to_indented_pretty_prints_the_xml() {
+        let feed = AtomFeedBuilder::<chrono::Utc>::new("Example Feed")
+            .id("urn:uuid:feed-1")
+            .entries::<&str>(vec![])
+            .build();
+
+        let xml = feed.write_to_indented(Vec::new(), b' ', 2).unwrap();
+        let xml_str = String::from_utf8(xml.clone()).unwrap();
+
+        assert!(xml_str.contains("\n  <id>urn:uuid:feed-1</id>"));
+
+        let parsed = AtomFeed::read_from(xml.as_slice()).unwrap();
+        assert_eq!(parsed.id.as_deref(), Some("urn:uuid:feed-1"));
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn content_markdown_and_summary_markdown_render_to_html() {
+        let entry = AtomEntry::<chrono::Utc>::new("Entry One")
+            .content_markdown("# Hello\n\nSome **bold** text.")
+            .summary_markdown("A *summary*.");
+
+        match &entry.content {
+            Some(Content::Inline(text)) => {
+                assert!(matches!(text.text_type, TextType::Html));
+                assert!(text.value.contains("<h1>Hello</h1>"));
+                assert!(text.value.contains("<strong>bold</strong>"));
+            }
+            other => panic!("expected rendered html content, got {other:?}"),
+        }
+
+        let summary = entry.summary.as_ref().expect("summary");
+        assert!(matches!(summary.text_type, TextType::Html));
+        assert!(summary.value.contains("<em>summary</em>"));
+    }
+}